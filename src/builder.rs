@@ -0,0 +1,168 @@
+use std::{collections::HashMap, fs, path::PathBuf, sync::Arc};
+
+use toml::Value;
+
+use crate::{
+    config::flatten_value,
+    error::Result,
+    format::{Format, Toml},
+    store::{DefaultStore, Store},
+    Config,
+};
+
+/// A loadable layer of configuration.
+///
+/// A `Source` is responsible for producing its own flattened view of the
+/// world (dot/index keys, same as [`Config::flatten`]); [`ConfigBuilder`]
+/// only has to overlay those maps in precedence order.
+pub trait Source {
+    /// Load and flatten this source.
+    ///
+    /// # Errors
+    /// Returns an error if the source cannot be read or parsed.
+    fn load(&self) -> Result<HashMap<String, Value>>;
+}
+
+/// A file on disk used as a [`Source`].
+///
+/// The format is picked from the file extension, same as [`Config::from_file`]:
+/// `.toml` by default, `.json`/`.yaml`/`.yml` when the matching feature is
+/// enabled.
+pub struct File {
+    path: PathBuf,
+}
+
+impl File {
+    /// Point at a config file on disk. Nothing is read until the source is
+    /// loaded by a [`ConfigBuilder`].
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Source for File {
+    fn load(&self) -> Result<HashMap<String, Value>> {
+        let content = fs::read_to_string(&self.path)?;
+
+        let root: Value = match self.path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "json")]
+            Some("json") => crate::format::Json::parse(&content)?,
+            #[cfg(feature = "yaml")]
+            Some("yaml" | "yml") => crate::format::Yaml::parse(&content)?,
+            _ => Toml::parse(&content)?,
+        };
+
+        let mut flat = HashMap::new();
+        flatten_value(&mut flat, "", root);
+        Ok(flat)
+    }
+}
+
+/// Builds a [`Config`] by layering several sources with well-defined
+/// precedence.
+///
+/// Lowest to highest precedence:
+///
+/// 1. [`ConfigBuilder::add_defaults`]
+/// 2. [`ConfigBuilder::add_source`] (in the order added)
+/// 3. [`ConfigBuilder::add_overrides`]
+///
+/// Within a layer, later calls win over earlier ones.
+///
+/// ## Array-of-tables merging
+///
+/// Sources are merged key-by-key on their *flattened* form, so a
+/// higher-precedence layer that only sets `runners[0].name` replaces just
+/// that element's `name` field, not the whole `runners` array. Sibling
+/// keys like `server.auth.method` are therefore untouched by an override
+/// that only targets `server.host`. To replace an array wholesale, a
+/// higher layer must redefine every index the lower layer set.
+#[derive(Default)]
+pub struct ConfigBuilder<S = DefaultStore> {
+    defaults: Vec<Box<dyn Source>>,
+    sources: Vec<Box<dyn Source>>,
+    overrides: Vec<Box<dyn Source>>,
+    _store: std::marker::PhantomData<S>,
+}
+
+impl<S> ConfigBuilder<S> {
+    /// Start building a layered configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            defaults: Vec::new(),
+            sources: Vec::new(),
+            overrides: Vec::new(),
+            _store: std::marker::PhantomData,
+        }
+    }
+
+    /// Add a lowest-precedence layer, applied before any other source.
+    #[must_use]
+    pub fn add_defaults(mut self, source: impl Source + 'static) -> Self {
+        self.defaults.push(Box::new(source));
+        self
+    }
+
+    /// Add a mid-precedence layer. Sources are applied in the order added.
+    #[must_use]
+    pub fn add_source(mut self, source: impl Source + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+
+    /// Add a highest-precedence layer, applied after every other source.
+    #[must_use]
+    pub fn add_overrides(mut self, source: impl Source + 'static) -> Self {
+        self.overrides.push(Box::new(source));
+        self
+    }
+}
+
+impl<S> ConfigBuilder<S>
+where
+    S: Store,
+{
+    /// Load every layer and merge them into a single immutable [`Config`].
+    ///
+    /// # Errors
+    /// Returns an error if any layer fails to load or parse.
+    pub fn build(self) -> Result<Config<S>> {
+        let mut store = S::default();
+
+        for layer in [self.defaults, self.sources, self.overrides] {
+            for source in layer {
+                for (key, value) in source.load()? {
+                    store.insert(key, value);
+                }
+            }
+        }
+
+        Ok(Config::from_store(Arc::new(store)))
+    }
+}
+
+impl Config {
+    /// Start layering configuration sources with explicit precedence,
+    /// using the default store.
+    ///
+    /// See [`ConfigBuilder`] for the merge semantics.
+    #[must_use]
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::new()
+    }
+}
+
+impl<S> Config<S>
+where
+    S: Store,
+{
+    /// Start layering configuration sources with explicit precedence,
+    /// using a custom store.
+    ///
+    /// See [`ConfigBuilder`] for the merge semantics.
+    #[must_use]
+    pub fn builder_with() -> ConfigBuilder<S> {
+        ConfigBuilder::new()
+    }
+}
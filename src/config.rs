@@ -4,6 +4,7 @@ use toml::Value;
 
 use crate::{
     error::Result,
+    format::{Format, Toml},
     store::{DefaultStore, Store},
 };
 
@@ -47,11 +48,28 @@ impl<S> Clone for Config<S> {
     }
 }
 
+impl<S> Config<S> {
+    /// Wrap an already-populated store, bypassing the usual parse step.
+    ///
+    /// Used internally by [`crate::ConfigBuilder`] and [`crate::MutableConfig`],
+    /// which build up a store through their own means before handing back a
+    /// read-only `Config`.
+    pub(crate) fn from_store(store: Arc<S>) -> Self {
+        Self { store }
+    }
+}
+
 impl Config {
-    /// Load configuration from a TOML file using the default store.
+    /// Load configuration from a file using the default store.
+    ///
+    /// The format is picked from the file extension: `.toml` (the
+    /// default), `.json` (with the `json` feature), or `.yaml`/`.yml`
+    /// (with the `yaml` feature). An unrecognized extension is parsed as
+    /// TOML.
     ///
     /// # Errors
-    /// Returns an error if the file cannot be read or contains invalid TOML.
+    /// Returns an error if the file cannot be read or contains invalid
+    /// content for its format.
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
         Self::from_file_with(path)
     }
@@ -63,6 +81,26 @@ impl Config {
     pub fn from_toml(content: &str) -> Result<Self> {
         Self::from_toml_with(content)
     }
+
+    /// Load configuration from a JSON string using the default store.
+    ///
+    /// # Errors
+    /// Returns an error if the string contains invalid JSON, or values with
+    /// no TOML equivalent (e.g. `null`).
+    #[cfg(feature = "json")]
+    pub fn from_json(content: &str) -> Result<Self> {
+        Self::from_json_with(content)
+    }
+
+    /// Load configuration from a YAML string using the default store.
+    ///
+    /// # Errors
+    /// Returns an error if the string contains invalid YAML, or values with
+    /// no TOML equivalent (e.g. `null`).
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        Self::from_yaml_with(content)
+    }
 }
 
 // This is me having fun with macros
@@ -82,13 +120,23 @@ impl<S> Config<S>
 where
     S: Store,
 {
-    /// Load configuration from a TOML file using a custom store.
+    /// Load configuration from a file using a custom store.
+    ///
+    /// The format is picked from the file extension, same as [`Config::from_file`].
     ///
     /// # Errors
-    /// Returns an error if the file cannot be read or contains invalid TOML.
+    /// Returns an error if the file cannot be read or contains invalid
+    /// content for its format.
     pub fn from_file_with<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let content = fs::read_to_string(path)?;
-        Self::from_toml_with(&content)
+        let content = fs::read_to_string(path.as_ref())?;
+
+        match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "json")]
+            Some("json") => Self::from_json_with(&content),
+            #[cfg(feature = "yaml")]
+            Some("yaml" | "yml") => Self::from_yaml_with(&content),
+            _ => Self::from_toml_with(&content),
+        }
     }
 
     /// Load configuration from a TOML string using a custom store.
@@ -96,7 +144,32 @@ where
     /// # Errors
     /// Returns an error if the string contains invalid TOML.
     pub fn from_toml_with(content: &str) -> Result<Self> {
-        let root: Value = toml::from_str(content)?;
+        Self::from_format_with::<Toml>(content)
+    }
+
+    /// Load configuration from a JSON string using a custom store.
+    ///
+    /// # Errors
+    /// Returns an error if the string contains invalid JSON, or values with
+    /// no TOML equivalent (e.g. `null`).
+    #[cfg(feature = "json")]
+    pub fn from_json_with(content: &str) -> Result<Self> {
+        Self::from_format_with::<crate::format::Json>(content)
+    }
+
+    /// Load configuration from a YAML string using a custom store.
+    ///
+    /// # Errors
+    /// Returns an error if the string contains invalid YAML, or values with
+    /// no TOML equivalent (e.g. `null`).
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml_with(content: &str) -> Result<Self> {
+        Self::from_format_with::<crate::format::Yaml>(content)
+    }
+
+    /// Parse `content` with `F` and flatten the resulting tree.
+    fn from_format_with<F: Format>(content: &str) -> Result<Self> {
+        let root: Value = F::parse(content)?;
 
         let mut store = S::default();
         flatten_value(&mut store, "", root);
@@ -113,9 +186,20 @@ where
     }
 
     /// Retrieve a raw TOML value by flattened key.
+    ///
+    /// Falls back to indexing into a stored primitive array when the key
+    /// ends in one or more `[n]` suffixes the flat store can't satisfy
+    /// directly, e.g. `ports[0]` against a flattened `ports = [80, 443]`.
     #[must_use]
     pub fn get(&self, key: &str) -> Option<&Value> {
-        self.store.get(key)
+        self.store
+            .get(key)
+            .or_else(|| crate::path::resolve(&*self.store, key))
+    }
+
+    /// Iterate over every flattened `(key, value)` pair in the store.
+    pub(crate) fn entries(&self) -> S::Iter<'_> {
+        self.store.iter()
     }
 
     impl_getters! {
@@ -130,7 +214,7 @@ where
     /// Strings preserve their raw content.
     /// Non-strings use TOML's display representation.
     pub fn flatten(&self) -> impl Iterator<Item = (String, String)> + '_ {
-        self.store.iter().map(|(k, v)| {
+        self.entries().map(|(k, v)| {
             let value = v
                 .as_str()
                 .map_or_else(|| v.to_string(), ToString::to_string);
@@ -155,7 +239,7 @@ where
 ///
 /// - `{ a = { b = 1 } }` → `a.b = 1`
 /// - `{ a = [ { x = 1 } ] }` → `a[0].x = 1`
-fn flatten_value<S: Store>(store: &mut S, prefix: &str, value: Value) {
+pub(crate) fn flatten_value<S: Store>(store: &mut S, prefix: &str, value: Value) {
     match value {
         Value::Table(table) => {
             for (k, v) in table {
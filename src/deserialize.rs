@@ -0,0 +1,120 @@
+use serde::de::DeserializeOwned;
+use toml::Value;
+
+use crate::{
+    error::{Error, Result},
+    path::{self, PathSegment},
+    store::Store,
+    Config,
+};
+
+impl<S> Config<S>
+where
+    S: Store,
+{
+    /// Deserialize the subtree rooted at `key` into `T`.
+    ///
+    /// The flattened store is reassembled into a nested `Value` tree before
+    /// handing it to serde, so `get_into::<DockerConfig>("runners[0].docker")`
+    /// works the same as if the original nested TOML had been deserialized
+    /// directly.
+    ///
+    /// # Errors
+    /// Returns [`Error::NotFound`] if no stored key falls under `key`, or
+    /// [`Error::Deserialize`] if the subtree doesn't match `T`'s shape.
+    pub fn get_into<T: DeserializeOwned>(&self, key: &str) -> Result<T> {
+        let tree = self
+            .subtree(key)
+            .ok_or_else(|| Error::NotFound(key.to_string()))?;
+        tree.try_into()
+            .map_err(|err: toml::de::Error| Error::Deserialize(err.to_string()))
+    }
+
+    /// Deserialize the whole configuration into `T`.
+    ///
+    /// # Errors
+    /// Returns [`Error::Deserialize`] if the configuration doesn't match
+    /// `T`'s shape.
+    pub fn try_deserialize<T: DeserializeOwned>(&self) -> Result<T> {
+        self.tree()
+            .try_into()
+            .map_err(|err: toml::de::Error| Error::Deserialize(err.to_string()))
+    }
+
+    /// Rebuild the full nested `Value` tree from the flattened store.
+    fn tree(&self) -> Value {
+        let mut root = Value::Table(toml::map::Map::new());
+        for (key, value) in self.entries() {
+            insert_path(&mut root, key, value.clone());
+        }
+        root
+    }
+
+    /// Rebuild the nested subtree rooted at `key`, or `None` if nothing in
+    /// the store falls under that prefix.
+    fn subtree(&self, key: &str) -> Option<Value> {
+        if let Some(value) = self.get(key) {
+            return Some(value.clone());
+        }
+
+        let prefix = format!("{key}.");
+        let indexed = format!("{key}[");
+        let mut root = Value::Table(toml::map::Map::new());
+        let mut found = false;
+
+        for (stored_key, value) in self.entries() {
+            if let Some(rest) = stored_key.strip_prefix(&prefix) {
+                insert_path(&mut root, rest, value.clone());
+                found = true;
+            } else if stored_key.starts_with(&indexed) {
+                // `runners[0].name` under `runners`: keep the `[0]...` tail.
+                insert_path(&mut root, &stored_key[key.len()..], value.clone());
+                found = true;
+            }
+        }
+
+        found.then_some(root)
+    }
+}
+
+/// Insert `value` at `path` into `node`, creating tables/arrays as needed.
+///
+/// Shares its tokenizer with [`crate::path`] rather than re-implementing
+/// `Key`/`Index` splitting here.
+fn insert_path(node: &mut Value, path: &str, value: Value) {
+    insert_segments(node, &path::parse(path), value);
+}
+
+fn insert_segments(node: &mut Value, segments: &[PathSegment], value: Value) {
+    let Some((head, rest)) = segments.split_first() else {
+        *node = value;
+        return;
+    };
+
+    match head {
+        PathSegment::Key(name) => {
+            if !matches!(node, Value::Table(_)) {
+                *node = Value::Table(toml::map::Map::new());
+            }
+            let Value::Table(table) = node else {
+                unreachable!()
+            };
+            let entry = table
+                .entry(name.clone())
+                .or_insert_with(|| Value::Table(toml::map::Map::new()));
+            insert_segments(entry, rest, value);
+        }
+        PathSegment::Index(index) => {
+            if !matches!(node, Value::Array(_)) {
+                *node = Value::Array(Vec::new());
+            }
+            let Value::Array(array) = node else {
+                unreachable!()
+            };
+            while array.len() <= *index {
+                array.push(Value::Table(toml::map::Map::new()));
+            }
+            insert_segments(&mut array[*index], rest, value);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use toml::Value;
+
+use crate::{builder::Source, error::Result, store::Store, Config};
+
+/// An environment-variable overlay used as a [`Source`].
+///
+/// Collects process env vars matching `prefix`, strips the prefix,
+/// lowercases what's left, and translates `separator` (`__` by default)
+/// into the `.` nesting the store already uses. `APP_SERVER__HOST` with
+/// prefix `APP_` becomes the flattened key `server.host`. A segment that
+/// parses as a plain integer is treated as an array index instead of a
+/// table key, so `APP_RUNNERS__0__NAME` becomes `runners[0].name` and can
+/// override a value produced by `flatten_value`'s array-of-tables handling.
+///
+/// Values are parsed as an integer, then a boolean, then a float, falling
+/// back to a string if none of those match.
+pub struct Env {
+    prefix: String,
+    separator: String,
+}
+
+impl Env {
+    /// Collect env vars starting with `prefix`, using `__` as the nested-key
+    /// separator.
+    pub fn prefix(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            separator: "__".to_string(),
+        }
+    }
+
+    /// Override the nested-key separator (default `__`).
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+}
+
+impl Source for Env {
+    fn load(&self) -> Result<HashMap<String, Value>> {
+        let mut flat = HashMap::new();
+
+        for (name, raw) in std::env::vars_os() {
+            // Only vars matching our prefix are relevant, so an unrelated
+            // non-UTF-8 var elsewhere in the environment can't panic the load.
+            let Some(name) = name.to_str() else {
+                continue;
+            };
+            let Some(rest) = name.strip_prefix(&self.prefix) else {
+                continue;
+            };
+            let Some(raw) = raw.to_str() else {
+                continue;
+            };
+            let key = translate_key(&rest.to_lowercase(), &self.separator);
+            flat.insert(key, parse_env_value(raw));
+        }
+
+        Ok(flat)
+    }
+}
+
+/// Translate a lowercased, separator-joined env var remainder into a flat
+/// key, treating purely-numeric segments as array indices: `server__0__name`
+/// with separator `__` becomes `server[0].name`, not `server.0.name`.
+fn translate_key(lowered: &str, separator: &str) -> String {
+    let mut key = String::new();
+
+    for segment in lowered.split(separator) {
+        if let Ok(index) = segment.parse::<usize>() {
+            key.push_str(&format!("[{index}]"));
+        } else {
+            if !key.is_empty() {
+                key.push('.');
+            }
+            key.push_str(segment);
+        }
+    }
+
+    key
+}
+
+fn parse_env_value(raw: &str) -> Value {
+    if let Ok(i) = raw.parse::<i64>() {
+        Value::Integer(i)
+    } else if let Ok(b) = raw.parse::<bool>() {
+        Value::Boolean(b)
+    } else if let Ok(f) = raw.parse::<f64>() {
+        Value::Float(f)
+    } else {
+        Value::String(raw.to_string())
+    }
+}
+
+impl Config {
+    /// Load configuration purely from env vars matching `prefix`, using the
+    /// default store.
+    ///
+    /// Shorthand for `Config::builder().add_source(Env::prefix(prefix)).build()`.
+    /// To overlay env vars on top of file-based config (env wins), add
+    /// [`Env`] as an override layer on [`crate::ConfigBuilder`] instead.
+    ///
+    /// # Errors
+    /// Currently infallible, but returns `Result` to match the other
+    /// constructors and allow future env-parsing failures to surface.
+    pub fn with_env(prefix: impl Into<String>) -> Result<Self> {
+        Self::builder().add_source(Env::prefix(prefix)).build()
+    }
+}
+
+impl<S> Config<S>
+where
+    S: Store,
+{
+    /// Load configuration purely from env vars matching `prefix`, using a
+    /// custom store.
+    ///
+    /// # Errors
+    /// Currently infallible, but returns `Result` to match the other
+    /// constructors and allow future env-parsing failures to surface.
+    pub fn with_env_with(prefix: impl Into<String>) -> Result<Self> {
+        Self::builder_with().add_source(Env::prefix(prefix)).build()
+    }
+}
@@ -14,6 +14,23 @@ pub enum Error {
 
     #[error("Type mismatch: expected {expected}, found {found}")]
     TypeMismatch { expected: String, found: String },
+
+    #[cfg(feature = "json")]
+    #[error("JSON parsing error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[cfg(feature = "yaml")]
+    #[error("YAML parsing error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error("Value has no TOML equivalent: {0}")]
+    UnsupportedValue(String),
+
+    #[error("Failed to deserialize configuration: {0}")]
+    Deserialize(String),
+
+    #[error("MutableConfig is frozen and can no longer be mutated")]
+    Frozen,
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
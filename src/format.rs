@@ -0,0 +1,125 @@
+use toml::Value;
+
+use crate::error::Result;
+
+/// A parseable configuration format.
+///
+/// Every format funnels into the same `toml::Value` tree, so once a
+/// `Format` has parsed its input, the rest of the pipeline (`flatten_value`,
+/// the getters, [`crate::Config::get`]) works identically regardless of
+/// where the bytes came from.
+pub trait Format {
+    /// Parse `content` into a `Value` tree.
+    ///
+    /// # Errors
+    /// Returns an error if `content` is not valid for this format, or if it
+    /// contains a shape TOML has no equivalent for (e.g. JSON `null`).
+    fn parse(content: &str) -> Result<Value>;
+}
+
+/// Plain TOML, the format `tomldir` has always understood.
+pub struct Toml;
+
+impl Format for Toml {
+    fn parse(content: &str) -> Result<Value> {
+        Ok(toml::from_str(content)?)
+    }
+}
+
+/// JSON, behind the `json` feature.
+#[cfg(feature = "json")]
+pub struct Json;
+
+#[cfg(feature = "json")]
+impl Format for Json {
+    fn parse(content: &str) -> Result<Value> {
+        let root: serde_json::Value = serde_json::from_str(content)?;
+        json_to_value(root)
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_to_value(json: serde_json::Value) -> Result<Value> {
+    use crate::error::Error;
+
+    Ok(match json {
+        serde_json::Value::Null => {
+            return Err(Error::UnsupportedValue(
+                "JSON null has no TOML equivalent".to_string(),
+            ))
+        }
+        serde_json::Value::Bool(b) => Value::Boolean(b),
+        serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Value::Integer(i)
+            } else if let Some(f) = n.as_f64() {
+                Value::Float(f)
+            } else {
+                return Err(Error::UnsupportedValue(format!(
+                    "JSON number {n} has no TOML equivalent"
+                )));
+            }
+        }
+        serde_json::Value::String(s) => Value::String(s),
+        serde_json::Value::Array(arr) => {
+            Value::Array(arr.into_iter().map(json_to_value).collect::<Result<_>>()?)
+        }
+        serde_json::Value::Object(map) => {
+            let mut table = toml::map::Map::new();
+            for (k, v) in map {
+                table.insert(k, json_to_value(v)?);
+            }
+            Value::Table(table)
+        }
+    })
+}
+
+/// YAML, behind the `yaml` feature.
+#[cfg(feature = "yaml")]
+pub struct Yaml;
+
+#[cfg(feature = "yaml")]
+impl Format for Yaml {
+    fn parse(content: &str) -> Result<Value> {
+        let root: serde_yaml::Value = serde_yaml::from_str(content)?;
+        yaml_to_value(root)
+    }
+}
+
+#[cfg(feature = "yaml")]
+fn yaml_to_value(yaml: serde_yaml::Value) -> Result<Value> {
+    use crate::error::Error;
+
+    Ok(match yaml {
+        serde_yaml::Value::Null => {
+            return Err(Error::UnsupportedValue(
+                "YAML null has no TOML equivalent".to_string(),
+            ))
+        }
+        serde_yaml::Value::Bool(b) => Value::Boolean(b),
+        serde_yaml::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Value::Integer(i)
+            } else if let Some(f) = n.as_f64() {
+                Value::Float(f)
+            } else {
+                return Err(Error::UnsupportedValue(format!(
+                    "YAML number {n} has no TOML equivalent"
+                )));
+            }
+        }
+        serde_yaml::Value::String(s) => Value::String(s),
+        serde_yaml::Value::Sequence(seq) => {
+            Value::Array(seq.into_iter().map(yaml_to_value).collect::<Result<_>>()?)
+        }
+        serde_yaml::Value::Mapping(map) => {
+            let mut table = toml::map::Map::new();
+            for (k, v) in map {
+                let key = k.as_str().map_or_else(|| format!("{k:?}"), String::from);
+                table.insert(key, yaml_to_value(v)?);
+            }
+            Value::Table(table)
+        }
+        serde_yaml::Value::Tagged(tagged) => yaml_to_value(tagged.value)?,
+    })
+}
@@ -1,8 +1,22 @@
+mod builder;
 mod config;
+mod deserialize;
+mod env;
 mod error;
+mod format;
+mod mutable;
+mod path;
 mod store;
 
+pub use builder::{ConfigBuilder, File, Source};
 pub use config::Config;
+pub use env::Env;
 pub use error::{Error, Result};
+pub use format::Format;
+#[cfg(feature = "json")]
+pub use format::Json;
+#[cfg(feature = "yaml")]
+pub use format::Yaml;
+pub use mutable::MutableConfig;
 pub use store::{DefaultStore, Store};
 pub use toml::Value;
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use toml::Value;
+
+use crate::{
+    error::{Error, Result},
+    store::{DefaultStore, Store},
+    Config,
+};
+
+/// A mutable configuration store that can be [`freeze`](MutableConfig::freeze)d
+/// into today's read-only [`Config`].
+///
+/// Mirrors the external `config` crate's `ConfigStore::{Mutable, Frozen}`
+/// split: `tomldir`'s usual model is "parsed once, then read-only", and
+/// `MutableConfig` makes that a real state transition instead of a
+/// convention. Once frozen, `set`/`merge` return [`Error::Frozen`] instead
+/// of silently succeeding.
+pub struct MutableConfig<S = DefaultStore> {
+    store: S,
+    frozen: bool,
+}
+
+impl<S> Default for MutableConfig<S>
+where
+    S: Store,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> MutableConfig<S>
+where
+    S: Store,
+{
+    /// Start with an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            store: S::default(),
+            frozen: false,
+        }
+    }
+
+    /// Set a single flattened key.
+    ///
+    /// # Errors
+    /// Returns [`Error::Frozen`] if called after [`MutableConfig::freeze`].
+    pub fn set(&mut self, key: impl Into<String>, value: Value) -> Result<()> {
+        if self.frozen {
+            return Err(Error::Frozen);
+        }
+        self.store.insert(key.into(), value);
+        Ok(())
+    }
+
+    /// Overlay every key from `other` onto this store.
+    ///
+    /// # Errors
+    /// Returns [`Error::Frozen`] if called after [`MutableConfig::freeze`].
+    pub fn merge(&mut self, other: &Config<S>) -> Result<()> {
+        if self.frozen {
+            return Err(Error::Frozen);
+        }
+        for (key, value) in other.entries() {
+            self.store.insert(key.clone(), value.clone());
+        }
+        Ok(())
+    }
+
+    /// Freeze the current state into an immutable, `Arc`-backed [`Config`].
+    ///
+    /// Subsequent `set`/`merge` calls on this `MutableConfig` return
+    /// [`Error::Frozen`]; calling `freeze` a second time does too.
+    ///
+    /// # Errors
+    /// Returns [`Error::Frozen`] if this `MutableConfig` was already frozen.
+    pub fn freeze(&mut self) -> Result<Config<S>> {
+        if self.frozen {
+            return Err(Error::Frozen);
+        }
+        self.frozen = true;
+        Ok(Config::from_store(Arc::new(std::mem::take(
+            &mut self.store,
+        ))))
+    }
+}
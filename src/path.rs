@@ -0,0 +1,94 @@
+use toml::Value;
+
+use crate::store::Store;
+
+/// One segment of a dotted config path.
+///
+/// Modeled on the external `config` crate's path parser: an identifier
+/// optionally followed by one or more bracketed indices, e.g.
+/// `ports[0]` tokenizes to `[Key("ports"), Index(0)]`.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Tokenize `path` (`.`-separated identifiers, each optionally carrying
+/// `[n]` index suffixes) into its segments, left to right.
+pub(crate) fn parse(path: &str) -> Vec<PathSegment> {
+    let mut segments = Vec::new();
+
+    for part in path.split('.') {
+        let (name, mut rest) = match part.find('[') {
+            Some(pos) => (&part[..pos], &part[pos..]),
+            None => (part, ""),
+        };
+        if !name.is_empty() {
+            segments.push(PathSegment::Key(name.to_string()));
+        }
+        while let Some(end) = rest.find(']') {
+            if let Ok(index) = rest[1..end].parse::<usize>() {
+                segments.push(PathSegment::Index(index));
+            }
+            rest = &rest[end + 1..];
+        }
+    }
+
+    segments
+}
+
+/// Re-join `segments` back into a flat key, re-emitting `[n]` for any
+/// `Index` segment so interior indices (e.g. the `[0]` in
+/// `runners[0].tags`) aren't lost — only the trailing index run that
+/// `resolve` peels off for array-walking should ever be excluded from this.
+fn rebuild_key(segments: &[PathSegment]) -> String {
+    let mut key = String::new();
+    for segment in segments {
+        match segment {
+            PathSegment::Key(name) => {
+                if !key.is_empty() {
+                    key.push('.');
+                }
+                key.push_str(name);
+            }
+            PathSegment::Index(index) => {
+                key.push_str(&format!("[{index}]"));
+            }
+        }
+    }
+    key
+}
+
+/// Resolve `key` against `store` when a flat lookup misses because `key`
+/// ends in one or more `[n]` indices into a primitive array the store kept
+/// whole (see `flatten_value`'s array-of-tables vs. primitive-array split).
+///
+/// Returns `None` on a type or bounds mismatch rather than panicking.
+pub(crate) fn resolve<'a, S: Store>(store: &'a S, key: &str) -> Option<&'a Value> {
+    let segments = parse(key);
+
+    let split = segments
+        .iter()
+        .rposition(|segment| matches!(segment, PathSegment::Key(_)))
+        .map_or(0, |pos| pos + 1);
+
+    if split == segments.len() {
+        // No trailing indices, so this isn't something a flat lookup could
+        // ever have missed for array-indexing reasons.
+        return None;
+    }
+
+    let mut value = store.get(&rebuild_key(&segments[..split]))?;
+
+    for segment in &segments[split..] {
+        let PathSegment::Index(index) = segment else {
+            unreachable!("split marks the start of a pure Index run");
+        };
+        value = match value {
+            Value::Array(array) => array.get(*index)?,
+            _ => return None,
+        };
+    }
+
+    Some(value)
+}
@@ -1,5 +1,6 @@
-use tomldir::{Config, Value};
+use tomldir::{Config, Env, File, MutableConfig, Value};
 use indexmap::IndexMap;
+use serde::Deserialize;
 use std::collections::{BTreeMap, HashMap};
 
 #[test]
@@ -10,7 +11,7 @@ fn test_basic_load() {
         enabled = true
         ratio = 1.5
     "#;
-    let cfg = Config::from_str(toml).unwrap();
+    let cfg = Config::from_toml(toml).unwrap();
     assert_eq!(cfg.get_string("title").unwrap(), "Test");
     assert_eq!(cfg.get_int("count").unwrap(), 10);
     assert!(cfg.get_bool("enabled").unwrap());
@@ -25,7 +26,7 @@ fn test_nested_table_flattening() {
         [server.auth]
         method = "token"
     "#;
-    let cfg = Config::from_str(toml).unwrap();
+    let cfg = Config::from_toml(toml).unwrap();
     assert_eq!(cfg.get_string("server.host").unwrap(), "localhost");
     assert_eq!(cfg.get_string("server.auth.method").unwrap(), "token");
 }
@@ -38,7 +39,7 @@ fn test_array_of_tables_flattening() {
         [[users]]
         name = "Bob"
     "#;
-    let cfg = Config::from_str(toml).unwrap();
+    let cfg = Config::from_toml(toml).unwrap();
     assert_eq!(cfg.get_string("users[0].name").unwrap(), "Alice");
     assert_eq!(cfg.get_string("users[1].name").unwrap(), "Bob");
 }
@@ -48,11 +49,16 @@ fn test_primitive_arrays() {
     let toml = r#"
         ports = [80, 443]
     "#;
-    let cfg = Config::from_str(toml).unwrap();
+    let cfg = Config::from_toml(toml).unwrap();
     let val = cfg.get("ports").unwrap();
     // With toml::Value, checking type is via type_str()
     assert_eq!(val.type_str(), "array");
-    assert!(cfg.get("ports[0]").is_none());
+
+    // `ports[0]` isn't a flat key on its own, but `get` resolves it by
+    // indexing into the stored `ports` array.
+    assert_eq!(cfg.get_int("ports[0]"), Some(80));
+    assert_eq!(cfg.get_int("ports[1]"), Some(443));
+    assert!(cfg.get_int("ports[2]").is_none());
 }
 
 #[test]
@@ -62,8 +68,8 @@ fn test_flatten_export() {
         debug = true
         rate = 5.5
     "#;
-    let cfg = Config::from_str(toml).unwrap();
-    let flat = cfg.flatten();
+    let cfg = Config::from_toml(toml).unwrap();
+    let flat: HashMap<String, String> = cfg.flatten().collect();
     assert_eq!(flat.get("app.debug"), Some(&"true".to_string()));
     assert_eq!(flat.get("app.rate"), Some(&"5.5".to_string()));
 }
@@ -79,10 +85,10 @@ fn test_indexmap_store_ordering() {
     "#;
     
     // Explicitly load into IndexMap
-    let cfg = Config::from_str_with_store::<IndexMap<String, Value>>(toml).unwrap();
-    
+    let cfg = Config::<IndexMap<String, Value>>::from_toml_with(toml).unwrap();
+
     // Verify order
-    let keys: Vec<_> = cfg.store().iter().map(|(k, _)| k.as_str()).collect();
+    let keys: Vec<_> = cfg.flatten().map(|(k, _)| k).collect();
     assert_eq!(keys, vec!["z", "a", "c", "b"]);
 }
 
@@ -93,7 +99,7 @@ fn test_flatten_generic_return() {
         id = 1
         name = "test"
     "#;
-    let cfg = Config::from_str(toml).unwrap();
+    let cfg = Config::from_toml(toml).unwrap();
 
     let flat_vec: Vec<(String, String)> = cfg.flatten_into();
     assert_eq!(flat_vec.len(), 2);
@@ -110,7 +116,7 @@ fn test_flatten_generic_return() {
 #[test]
 fn test_shared_semantics() {
     let toml = "val = 1";
-    let cfg = Config::from_str(toml).unwrap();
+    let cfg = Config::from_toml(toml).unwrap();
     let shared = cfg.shared();
     
     assert_eq!(cfg.get_int("val"), Some(1));
@@ -120,6 +126,180 @@ fn test_shared_semantics() {
 #[test]
 fn test_default_storage_is_hashmap() {
     let toml = "val = 1";
-    let cfg = Config::from_str(toml).unwrap();
+    let cfg = Config::from_toml(toml).unwrap();
     assert_eq!(cfg.get_int("val"), Some(1));
 }
+
+#[test]
+fn test_builder_layering_precedence() {
+    let defaults = std::env::temp_dir().join("tomldir_test_defaults.toml");
+    let layer = std::env::temp_dir().join("tomldir_test_layer.toml");
+    let overrides = std::env::temp_dir().join("tomldir_test_overrides.toml");
+
+    std::fs::write(&defaults, "[server]\nhost = \"0.0.0.0\"\nport = 80\n").unwrap();
+    std::fs::write(&layer, "[server]\nhost = \"localhost\"\n[server.auth]\nmethod = \"token\"\n").unwrap();
+    std::fs::write(&overrides, "[server]\nport = 9000\n").unwrap();
+
+    let cfg = Config::builder()
+        .add_defaults(File::new(&defaults))
+        .add_source(File::new(&layer))
+        .add_overrides(File::new(&overrides))
+        .build()
+        .unwrap();
+
+    // `layer` overrides `defaults`'s host, `overrides` overrides `layer`'s port,
+    // and the untouched sibling key `server.auth.method` survives both.
+    assert_eq!(cfg.get_string("server.host").unwrap(), "localhost");
+    assert_eq!(cfg.get_int("server.port").unwrap(), 9000);
+    assert_eq!(cfg.get_string("server.auth.method").unwrap(), "token");
+
+    std::fs::remove_file(&defaults).unwrap();
+    std::fs::remove_file(&layer).unwrap();
+    std::fs::remove_file(&overrides).unwrap();
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_from_json_with_feeds_same_flattening_pipeline() {
+    let json = r#"{
+        "server": { "host": "localhost", "auth": { "method": "token" } },
+        "users": [ { "name": "Alice" }, { "name": "Bob" } ]
+    }"#;
+    let cfg = Config::from_json(json).unwrap();
+    assert_eq!(cfg.get_string("server.host").unwrap(), "localhost");
+    assert_eq!(cfg.get_string("server.auth.method").unwrap(), "token");
+    assert_eq!(cfg.get_string("users[0].name").unwrap(), "Alice");
+    assert_eq!(cfg.get_string("users[1].name").unwrap(), "Bob");
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn test_from_yaml_with_feeds_same_flattening_pipeline() {
+    let yaml = "server:\n  host: localhost\n  auth:\n    method: token\n";
+    let cfg = Config::from_yaml(yaml).unwrap();
+    assert_eq!(cfg.get_string("server.host").unwrap(), "localhost");
+    assert_eq!(cfg.get_string("server.auth.method").unwrap(), "token");
+}
+
+#[test]
+fn test_env_source_overrides_file_values() {
+    std::env::set_var("TOMLDIR_TEST__SERVER__HOST", "override.example.com");
+    std::env::set_var("TOMLDIR_TEST__SERVER__PORT", "9000");
+
+    let base = std::env::temp_dir().join("tomldir_test_env_base.toml");
+    std::fs::write(&base, "[server]\nhost = \"localhost\"\nport = 80\n").unwrap();
+
+    let cfg = Config::builder()
+        .add_source(File::new(&base))
+        .add_overrides(Env::prefix("TOMLDIR_TEST__"))
+        .build()
+        .unwrap();
+
+    assert_eq!(cfg.get_string("server.host").unwrap(), "override.example.com");
+    assert_eq!(cfg.get_int("server.port").unwrap(), 9000);
+
+    std::fs::remove_file(&base).unwrap();
+    std::env::remove_var("TOMLDIR_TEST__SERVER__HOST");
+    std::env::remove_var("TOMLDIR_TEST__SERVER__PORT");
+}
+
+#[test]
+fn test_with_env_standalone() {
+    std::env::set_var("TOMLDIR_STANDALONE_VAL", "42");
+    let cfg = Config::with_env("TOMLDIR_STANDALONE_").unwrap();
+    assert_eq!(cfg.get_int("val"), Some(42));
+    std::env::remove_var("TOMLDIR_STANDALONE_VAL");
+}
+
+#[test]
+fn test_env_numeric_segment_maps_to_bracket_index() {
+    std::env::set_var("TOMLDIR_IDX__RUNNERS__0__NAME", "docker-runner");
+
+    let cfg = Config::with_env("TOMLDIR_IDX__").unwrap();
+    assert_eq!(cfg.get_string("runners[0].name").unwrap(), "docker-runner");
+
+    std::env::remove_var("TOMLDIR_IDX__RUNNERS__0__NAME");
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct DockerConfig {
+    image: String,
+    privileged: bool,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct AppConfig {
+    concurrent: i64,
+}
+
+#[test]
+fn test_get_into_deserializes_a_subtree() {
+    let toml = r#"
+        [[runners]]
+        name = "docker-runner"
+        [runners.docker]
+        image = "alpine:latest"
+        privileged = false
+    "#;
+    let cfg = Config::from_toml(toml).unwrap();
+
+    let docker: DockerConfig = cfg.get_into("runners[0].docker").unwrap();
+    assert_eq!(
+        docker,
+        DockerConfig {
+            image: "alpine:latest".to_string(),
+            privileged: false,
+        }
+    );
+
+    let err = cfg.get_into::<DockerConfig>("runners[0].missing").unwrap_err();
+    assert!(matches!(err, tomldir::Error::NotFound(_)));
+}
+
+#[test]
+fn test_try_deserialize_whole_config() {
+    let toml = "concurrent = 4";
+    let cfg = Config::from_toml(toml).unwrap();
+    let app: AppConfig = cfg.try_deserialize().unwrap();
+    assert_eq!(app, AppConfig { concurrent: 4 });
+}
+
+#[test]
+fn test_array_index_under_table_array_element() {
+    let toml = r#"
+        [[runners]]
+        name = "docker-runner"
+        tags = ["fast", "linux", "x86_64"]
+    "#;
+    let cfg = Config::from_toml(toml).unwrap();
+    assert_eq!(cfg.get_string("runners[0].tags[1]").unwrap(), "linux");
+}
+
+#[test]
+fn test_mutable_config_freeze() {
+    let mut builder = MutableConfig::<HashMap<String, Value>>::new();
+    builder.set("server.host", Value::String("localhost".to_string())).unwrap();
+    builder.set("server.port", Value::Integer(8080)).unwrap();
+
+    let cfg = builder.freeze().unwrap();
+    assert_eq!(cfg.get_string("server.host").unwrap(), "localhost");
+    assert_eq!(cfg.get_int("server.port").unwrap(), 8080);
+
+    // Post-freeze mutation is a typed error, not a silent no-op.
+    let err = builder.set("server.host", Value::String("changed".to_string())).unwrap_err();
+    assert!(matches!(err, tomldir::Error::Frozen));
+    assert!(builder.freeze().is_err());
+}
+
+#[test]
+fn test_mutable_config_merge() {
+    let base = Config::from_toml("val = 1\nkept = true").unwrap();
+
+    let mut builder = MutableConfig::<HashMap<String, Value>>::new();
+    builder.merge(&base).unwrap();
+    builder.set("val", Value::Integer(2)).unwrap();
+
+    let cfg = builder.freeze().unwrap();
+    assert_eq!(cfg.get_int("val"), Some(2));
+    assert!(cfg.get_bool("kept").unwrap());
+}